@@ -26,10 +26,14 @@ THE SOFTWARE.
 
 ---------------------------------------------------------------------------*/
 
-use std::sync::mpsc::{SyncSender, Receiver, SendError, TryRecvError};
+use std::sync::mpsc::{SyncSender, Receiver, SendError, RecvError, TryRecvError, RecvTimeoutError};
 use std::sync::mpsc::sync_channel;
-use std::collections::HashMap;
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::collections::{HashMap, BinaryHeap};
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
+use std::panic::{self, AssertUnwindSafe};
 use std::thread;
 
 /// select<T>
@@ -64,28 +68,156 @@ pub fn select<T>(mut receivers: Vec<Receiver<T>>) -> Receiver<T> where T: Send +
     receiver
 }
 
+/// extracts a human readable message from a caught panic payload.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+  if let Some(message) = payload.downcast_ref::<&str>() {
+    message.to_string()
+  } else if let Some(message) = payload.downcast_ref::<String>() {
+    message.clone()
+  } else {
+    format!("actor panicked")
+  }
+}
+
 /// spawn_actor<T>
-/// 
+///
 /// Spawns a new actor in a new thread and returns
-/// the sender / receiver pairs.
-pub fn spawn_actor<A, T>(address: String, actor: Box<A>) -> (SyncSender<T>, Receiver<ActorEvent<T>>) 
-    where  T: Send + 'static,
-           A: Actor<T> + Send + 'static {
-    let mut actor = Box::new(actor);
-    let (tx0, rx0) = sync_channel::<ActorEvent<T>>(1);
+/// its input and call senders. The actor is (re)built
+/// from `factory` every time it is started, so the same
+/// thread can carry an actor through its on_start, run and
+/// on_stop/on_panic lifecycle across restarts while keeping
+/// its mailbox addresses stable. `mailbox` is a clone of the
+/// system's single shared ActorEvent sender, so every actor's
+/// events land in the one queue System::run drains.
+pub fn spawn_actor<T>(address: String, factory: Arc<dyn Fn() -> Box<dyn Actor<T>> + Send + Sync>, account: Account, mailbox: SyncSender<ActorEvent<T>>) -> (SyncSender<T>, SyncSender<Call<T>>)
+    where T: Send + 'static {
+    let tx0 = mailbox;
     let (tx1, rx1) = sync_channel::<T>(1);
+    let (tx2, rx2) = sync_channel::<Call<T>>(1);
+    let state = Arc::new(AtomicU8::new(ActorState::Starting as u8));
+    let receiver = TrackedReceiver::new(rx1, state, tx0.clone(), address.clone());
     thread::spawn(move || {
       tx0.send(ActorEvent::Started(address.clone())).unwrap();
-      actor.run(Sender::new(address.clone(), tx0.clone()), rx1);
+      let _ = tx0.send(ActorEvent::State(address.clone(), ActorState::Starting));
+      let mut attempt: u32 = 0;
+      loop {
+        let mut actor = factory();
+        actor.on_start();
+        let sender = Sender::new(address.clone(), tx0.clone(), account.clone());
+        let _ = tx0.send(ActorEvent::State(address.clone(), ActorState::Running));
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+          actor.run(sender, &receiver, &rx2);
+        }));
+        match outcome {
+          Ok(_) => {
+            actor.on_stop();
+            break
+          },
+          Err(payload) => {
+            let reason = panic_message(payload);
+            attempt += 1;
+            let hook_decision = actor.on_panic(reason.clone());
+            let (decision_tx, decision_rx) = sync_channel(1);
+            if tx0.send(ActorEvent::Panicked(address.clone(), reason, attempt, decision_tx)).is_err() {
+              break
+            }
+            let system_allows = decision_rx.recv().unwrap_or(false);
+            if hook_decision == Decision::Stop || !system_allows {
+              break
+            }
+          }
+        }
+      }
+      let _ = tx0.send(ActorEvent::State(address.clone(), ActorState::Stopped));
       tx0.send(ActorEvent::Stopped(address)).unwrap();
     });
-    (tx1, rx0)
+    (tx1, tx2)
+}
+
+/// ActorState
+///
+/// The lifecycle state of one actor, flipped by TrackedReceiver
+/// around its blocking recv() and reported to System, which
+/// forwards each transition as SystemEvent::StateChanged.
+/// System does not keep a record of the latest state per address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ActorState {
+  Starting = 0,
+  Running = 1,
+  Blocked = 2,
+  Stopped = 3
+}
+impl From<u8> for ActorState {
+  fn from(value: u8) -> ActorState {
+    match value {
+      1 => ActorState::Running,
+      2 => ActorState::Blocked,
+      3 => ActorState::Stopped,
+      _ => ActorState::Starting
+    }
+  }
+}
+
+/// TrackedReceiver<T>
+///
+/// Wraps an actor's input Receiver<T>, flipping a shared
+/// AtomicU8 to Blocked right before a blocking recv() and back
+/// to Running once a value arrives, and forwarding the same
+/// transitions to System as ActorEvent::State so operators get
+/// a live view of which actors are idle vs busy.
+pub struct TrackedReceiver<T> {
+  inner: Receiver<T>,
+  state: Arc<AtomicU8>,
+  mailbox: SyncSender<ActorEvent<T>>,
+  address: String
+}
+impl<T> TrackedReceiver<T> {
+  fn new(inner: Receiver<T>, state: Arc<AtomicU8>, mailbox: SyncSender<ActorEvent<T>>, address: String) -> TrackedReceiver<T> {
+    TrackedReceiver { inner, state, mailbox, address }
+  }
+  /// the most recently observed state for this receiver.
+  pub fn state(&self) -> ActorState {
+    ActorState::from(self.state.load(Ordering::SeqCst))
+  }
+  /// blocks for the next message, reporting Blocked while
+  /// waiting and Running once a value has arrived. The mailbox
+  /// push is a try_send, not a send: this runs on every message
+  /// hop, and a blocking send here could stall on a full mailbox
+  /// before this actor ever reaches inner.recv(), which is exactly
+  /// the hold-and-wait System::run's own routing sends are prone
+  /// to on the other side of a full actor input channel.
+  pub fn recv(&self) -> Result<T, RecvError> {
+    self.state.store(ActorState::Blocked as u8, Ordering::SeqCst);
+    let _ = self.mailbox.try_send(ActorEvent::State(self.address.clone(), ActorState::Blocked));
+    let result = self.inner.recv();
+    self.state.store(ActorState::Running as u8, Ordering::SeqCst);
+    let _ = self.mailbox.try_send(ActorEvent::State(self.address.clone(), ActorState::Running));
+    result
+  }
+  /// iterates messages the same way Receiver::iter() does.
+  pub fn iter(&self) -> TrackedIter<'_, T> {
+    TrackedIter { receiver: self }
+  }
+}
+
+/// TrackedIter<T>
+///
+/// Iterator returned from TrackedReceiver::iter().
+pub struct TrackedIter<'a, T> {
+  receiver: &'a TrackedReceiver<T>
+}
+impl<'a, T> Iterator for TrackedIter<'a, T> {
+  type Item = T;
+  fn next(&mut self) -> Option<T> {
+    self.receiver.recv().ok()
+  }
 }
 
 
 /// ActorEvent<T>
-/// 
-/// An actor event type. This event type is emitted 
+///
+/// An actor event type. This event type is emitted
 /// during the lifecycle of an actor, and is received
 /// by the system to signal actor start, actor stop and
 /// actor messages flowing between actors.
@@ -93,45 +225,227 @@ pub enum ActorEvent<T> {
   Started(String),
   Send(String, String, T),
   Publish(String, String, T),
+  Call(String, String, T, SyncSender<T>),
+  Schedule(String, String, T, Duration),
+  Interval(String, String, T, Duration, u64),
+  CancelInterval(u64),
+  Panicked(String, String, u32, SyncSender<bool>), // address, reason, attempt, restart decision
+  Discover(String, SyncSender<bool>),              // address, reply
+  Spawn(String, Arc<dyn Fn() -> Box<dyn Actor<T>> + Send + Sync>, RestartStrategy),
+  Unmount(String),
+  State(String, ActorState),                       // address, new state
   Stopped(String)
 }
 
+/// Decision
+///
+/// Returned from Actor::on_panic() to let the actor itself
+/// veto a restart that the system's RestartStrategy would
+/// otherwise allow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+  Restart,
+  Stop
+}
+
+/// RestartStrategy
+///
+/// Governs how System reacts to ActorEvent::Panicked for a
+/// given address.
+#[derive(Debug, Clone, Copy)]
+pub enum RestartStrategy {
+  Never,
+  Always,
+  UpTo(u32)
+}
+
 /// Actor<T>
-/// 
+///
 /// Base trait for which all other actors should
 /// implement. Provides a single input output
 /// type which is intended to be a simple message,
 /// or an enum for more advance message types.
 pub trait Actor<T: Send + 'static> {
-  fn run(&mut self, sender: Sender<T>, receiver: Receiver<T>);
+  fn run(&mut self, sender: Sender<T>, receiver: &TrackedReceiver<T>, calls: &Receiver<Call<T>>);
+  /// called before the first (and every restarted) run().
+  fn on_start(&mut self) {}
+  /// called after run() returns normally.
+  fn on_stop(&mut self) {}
+  /// called when run() panics. The default gives up.
+  fn on_panic(&mut self, _reason: String) -> Decision {
+    Decision::Stop
+  }
+}
+
+/// Call<T>
+///
+/// A single request/response envelope delivered to an
+/// actor's call receiver. Wraps the caller's value along
+/// with the reply channel used to send the result back.
+/// Dropping a Call without calling respond() closes the
+/// caller's receiver, causing its recv() to return Err
+/// rather than hang.
+pub struct Call<T> {
+  value: T,
+  reply: SyncSender<T>
+}
+impl<T> Call<T> {
+  /// returns a reference to the call's request value.
+  pub fn value(&self) -> &T {
+    &self.value
+  }
+  /// sends the response back to the caller, consuming the call.
+  pub fn respond(self, value: T) {
+    let _ = self.reply.send(value);
+  }
+}
+
+/// IntervalHandle
+///
+/// A cancellation token returned from send_interval().
+/// Pass it to Sender::cancel_interval() to stop further
+/// firings of the recurring message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalHandle(u64);
+
+/// global id generator for interval handles. ids must be
+/// assigned synchronously in send_interval() (before the
+/// system has seen the event) so the caller can cancel the
+/// interval immediately after scheduling it.
+static NEXT_INTERVAL_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Account
+///
+/// Credit-based flow-control accounting for one sending
+/// address, shared by every actor mounted there. Tracks
+/// outstanding (sent-but-not-yet-delivered) messages in a
+/// shared counter so a fast producer cannot unboundedly
+/// outpace a slow consumer through the central routing loop.
+/// Every increment (made by Sender::send / Sender::publish
+/// before emitting the event) is matched by exactly one
+/// decrement: made by System::run once the message has been
+/// handed to the destination or failed to be (including after
+/// the address is unmounted), or by the Sender itself if the
+/// event never reaches the mailbox, keeping debt conserved.
+#[derive(Clone)]
+pub struct Account {
+  debt: Arc<AtomicU64>,
+  high_water: u64,
+  low_water: u64
+}
+impl Account {
+  pub fn new(high_water: u64, low_water: u64) -> Account {
+    Account { debt: Arc::new(AtomicU64::new(0)), high_water, low_water }
+  }
+  /// current number of outstanding messages for this account.
+  pub fn debt(&self) -> u64 {
+    self.debt.load(Ordering::SeqCst)
+  }
+  fn increment(&self) {
+    self.debt.fetch_add(1, Ordering::SeqCst);
+  }
+  fn decrement(&self) {
+    self.debt.fetch_sub(1, Ordering::SeqCst);
+  }
+  /// parks the calling thread once debt has reached the
+  /// high-water mark, until it drains back down to the
+  /// low-water mark.
+  fn throttle(&self) {
+    if self.debt() >= self.high_water {
+      while self.debt() > self.low_water {
+        thread::yield_now();
+      }
+    }
+  }
 }
 
 /// Sender<T>
-/// 
+///
 /// A custom sender type given to actors to allow
-/// them to send messages to other actors in a 
+/// them to send messages to other actors in a
 /// system. Implements a simple send function
 /// with a address to the recipient actor.
 #[derive(Clone)]
 pub struct Sender<T> {
   address: String,
-  sender: SyncSender<ActorEvent<T>>
+  sender: SyncSender<ActorEvent<T>>,
+  account: Account
 }
 impl<T> Sender<T> {
-  pub fn new(address: String, sender: SyncSender<ActorEvent<T>>) -> Sender<T> {
-    Sender { address, sender }
+  pub fn new(address: String, sender: SyncSender<ActorEvent<T>>, account: Account) -> Sender<T> {
+    Sender { address, sender, account }
   }
-  /// sends a message to 1 actor at the given address.
+  /// sends a message to 1 actor at the given address. Blocks
+  /// first if this sender's outstanding debt has reached the
+  /// high-water mark (see Account).
   pub fn send(&self, to: &str, value: T) -> Result<(), SendError<ActorEvent<T>>>  {
+    self.account.throttle();
     let from = self.address.clone();
     let to   = to.to_string();
-    self.sender.send(ActorEvent::Send(from, to, value))
+    self.account.increment();
+    let result = self.sender.send(ActorEvent::Send(from, to, value));
+    if result.is_err() {
+      self.account.decrement();
+    }
+    result
   }
-  /// sends a message to N actors at the given address.
+  /// sends a message to N actors at the given address. Blocks
+  /// first if this sender's outstanding debt has reached the
+  /// high-water mark (see Account).
   pub fn publish(&self, to: &str, value: T) -> Result<(), SendError<ActorEvent<T>>>  {
+    self.account.throttle();
+    let from = self.address.clone();
+    let to   = to.to_string();
+    self.account.increment();
+    let result = self.sender.send(ActorEvent::Publish(from, to, value));
+    if result.is_err() {
+      self.account.decrement();
+    }
+    result
+  }
+  /// sends a message to 1 actor at the given address and
+  /// returns a receiver that yields the single reply. The
+  /// system routes the call to exactly one recipient chosen
+  /// via round-robin, the same as send().
+  pub fn call(&self, to: &str, value: T) -> Receiver<T> {
+    let from = self.address.clone();
+    let to   = to.to_string();
+    let (reply, receiver) = sync_channel(1);
+    // a closed receiver (target missing or gone) simply means
+    // the reply sender above is dropped without a response,
+    // which causes recv() on the returned receiver to error.
+    let _ = self.sender.send(ActorEvent::Call(from, to, value, reply));
+    receiver
+  }
+  /// schedules a message to be delivered to 1 actor once,
+  /// after the given delay has elapsed.
+  pub fn send_later(&self, to: &str, value: T, delay: Duration) -> Result<(), SendError<ActorEvent<T>>> {
+    let from = self.address.clone();
+    let to   = to.to_string();
+    self.sender.send(ActorEvent::Schedule(from, to, value, delay))
+  }
+  /// schedules a message to be delivered to 1 actor
+  /// repeatedly, every `every`, until cancelled via the
+  /// returned handle.
+  pub fn send_interval(&self, to: &str, value: T, every: Duration) -> IntervalHandle {
     let from = self.address.clone();
     let to   = to.to_string();
-    self.sender.send(ActorEvent::Publish(from, to, value))
+    let id   = NEXT_INTERVAL_ID.fetch_add(1, Ordering::Relaxed);
+    let _ = self.sender.send(ActorEvent::Interval(from, to, value, every, id));
+    IntervalHandle(id)
+  }
+  /// cancels a recurring message previously scheduled with
+  /// send_interval(). Already in-flight firings are not
+  /// affected, but no further firings will be scheduled.
+  pub fn cancel_interval(&self, handle: IntervalHandle) -> Result<(), SendError<ActorEvent<T>>> {
+    self.sender.send(ActorEvent::CancelInterval(handle.0))
+  }
+  /// returns whether any actor is currently registered at the
+  /// given address, so a caller can check before send()/call().
+  pub fn discover(&self, address: &str) -> bool {
+    let (reply, receiver) = sync_channel(1);
+    let _ = self.sender.send(ActorEvent::Discover(address.to_string(), reply));
+    receiver.recv().unwrap_or(false)
   }
 }
 
@@ -169,6 +483,57 @@ impl RoundRobin {
       None => 0
     }
   }
+  /// refreshes the counter for one address after its recipient
+  /// count has changed (e.g. a runtime register()), keeping the
+  /// current position if it is still in range.
+  fn rebuild(&mut self, address: &str, total: usize) {
+    let current = self.counters.get(address).map(|(_, current)| *current).unwrap_or(0);
+    let current = if current >= total { 0 } else { current };
+    self.counters.insert(address.to_string(), (total, current));
+  }
+  /// drops the counter for an address that has been unmounted.
+  fn remove(&mut self, address: &str) {
+    self.counters.remove(address);
+  }
+}
+
+/// ScheduledMessage<T>
+///
+/// A pending timer delivery. One-shot messages (send_later)
+/// carry `interval: None`; recurring messages (send_interval)
+/// carry their id and period so the run loop can re-arm them
+/// after firing.
+struct ScheduledMessage<T> {
+  to: String,
+  value: T,
+  interval: Option<(u64, Duration)>
+}
+
+/// TimerEntry<T>
+///
+/// A min-heap entry ordered purely by fire time (ties broken
+/// by insertion order). Kept separate from ScheduledMessage so
+/// the heap never requires T: Ord.
+struct TimerEntry<T> {
+  fire_at: Instant,
+  seq: u64,
+  message: ScheduledMessage<T>
+}
+impl<T> PartialEq for TimerEntry<T> {
+  fn eq(&self, other: &Self) -> bool {
+    self.fire_at == other.fire_at && self.seq == other.seq
+  }
+}
+impl<T> Eq for TimerEntry<T> {}
+impl<T> PartialOrd for TimerEntry<T> {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+impl<T> Ord for TimerEntry<T> {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    (self.fire_at, self.seq).cmp(&(other.fire_at, other.seq))
+  }
 }
 
 /// SystemEvent
@@ -184,42 +549,146 @@ pub enum SystemEvent {
   Started(String),                // address
   Forward(String, String, usize), // from, to, idx
   Error(String, String),          // address, reason
+  Restarted(String, u32),         // address, attempt
+  Pressure(String, u64),          // address, debt
+  Registered(String),             // address
+  Deregistered(String),           // address
+  StateChanged(String, ActorState), // address, new state
   Stopped(String)                 // actor
 }
 
+/// SystemHandle<T>
+///
+/// A cloneable, thread-safe handle for registering and
+/// deregistering actors in a running System without needing
+/// a reference to it (run() has already taken it by value).
+/// Both methods simply inject a control event into the
+/// shared mailbox; System::run performs the actual work the
+/// next time it drains that event.
+pub struct SystemHandle<T> {
+  mailbox: SyncSender<ActorEvent<T>>
+}
+impl<T> Clone for SystemHandle<T> {
+  fn clone(&self) -> Self {
+    SystemHandle { mailbox: self.mailbox.clone() }
+  }
+}
+impl<T: Send + 'static> SystemHandle<T> {
+  /// registers a new actor at `address`, spawned the same way
+  /// System::mount() would, with restarts governed by `strategy`.
+  pub fn register<F>(&self, address: &str, factory: F, strategy: RestartStrategy)
+      where F: Fn() -> Box<dyn Actor<T>> + Send + Sync + 'static {
+    let factory: Arc<dyn Fn() -> Box<dyn Actor<T>> + Send + Sync> = Arc::new(factory);
+    let _ = self.mailbox.send(ActorEvent::Spawn(address.to_string(), factory, strategy));
+  }
+  /// deregisters every actor mounted at `address`.
+  pub fn deregister(&self, address: &str) {
+    let _ = self.mailbox.send(ActorEvent::Unmount(address.to_string()));
+  }
+}
+
+/// capacity of the shared mailbox every actor's events land in.
+/// Must be large enough that a burst of concurrent producers can
+/// never fill it and block a sender inside TrackedReceiver::recv()
+/// (see its doc comment) while System::run is itself blocked
+/// routing into a full actor input channel elsewhere — that
+/// hold-and-wait is a deadlock. Sized generously rather than made
+/// unbounded so a runaway producer still backs up eventually.
+const MAILBOX_CAPACITY: usize = 4096;
+
 /// System<T>
-/// 
-/// An actor host and message routing system. 
+///
+/// An actor host and message routing system.
 /// Responsible for receiving messages from
 /// actors and forwarding them to other actors
 /// mounted within the system by simple address.
 pub struct System<T> {
-  receivers: Vec<Receiver<ActorEvent<T>>>,
-  senders: HashMap<String, Vec<SyncSender<T>>>
+  mailbox: SyncSender<ActorEvent<T>>,
+  inbox: Receiver<ActorEvent<T>>,
+  senders: HashMap<String, Vec<SyncSender<T>>>,
+  call_senders: HashMap<String, Vec<SyncSender<Call<T>>>>,
+  strategies: HashMap<String, RestartStrategy>,
+  accounts: HashMap<String, Account>,
+  high_water: u64,
+  low_water: u64,
+  live: u64
 }
 impl<T> System<T> where T: Send + Clone + 'static {
-  pub fn new() -> System<T> {
-    let receivers = Vec::new();
+  /// constructs a system with the given default high/low
+  /// water marks used for every mounted actor's Account.
+  pub fn new(high_water: u64, low_water: u64) -> System<T> {
+    let (mailbox, inbox) = sync_channel::<ActorEvent<T>>(MAILBOX_CAPACITY);
     let senders = HashMap::new();
-    System { receivers, senders }
+    let call_senders = HashMap::new();
+    let strategies = HashMap::new();
+    let accounts = HashMap::new();
+    System { mailbox, inbox, senders, call_senders, strategies, accounts, high_water, low_water, live: 0 }
   }
   /// mounts and spawns an actor causing its
   /// run() function to execute. The actor
   /// will suspend following its first call
   /// to send and resume when the system is
-  /// run()
-  pub fn mount<A: Actor<T> + Send + 'static>(&mut self, address: &str, a: Box<A>) {
+  /// run(). `factory` builds a fresh actor
+  /// instance both for the initial spawn and
+  /// for every restart permitted by `strategy`.
+  pub fn mount<F>(&mut self, address: &str, factory: F, strategy: RestartStrategy)
+      where F: Fn() -> Box<dyn Actor<T>> + Send + Sync + 'static {
     let address = address.to_string();
-    let (sender, receiver) = spawn_actor(address.clone(), a);
-    self.receivers.push(receiver);
-    if !self.senders.contains_key(&address) {
-      self.senders.insert(address.clone(), Vec::new());
-    }
-    match self.senders.get_mut(&address) {
-      Some(ref mut vec) => {
-        vec.push(sender)
-      },
-      None => {}
+    let factory: Arc<dyn Fn() -> Box<dyn Actor<T>> + Send + Sync> = Arc::new(factory);
+    self.spawn_and_register(address, factory, strategy);
+  }
+
+  /// returns a handle that can register/deregister actors at
+  /// runtime by injecting control events into the same
+  /// mailbox System::run() drains, even after run() has taken
+  /// ownership of self and is blocking on another thread.
+  pub fn handle(&self) -> SystemHandle<T> {
+    SystemHandle { mailbox: self.mailbox.clone() }
+  }
+
+  /// spawns an actor and records it in the address tables.
+  /// Shared by mount() (before run()) and the ActorEvent::Spawn
+  /// handler in run() (after run() owns self), so both paths
+  /// stay in sync.
+  fn spawn_and_register(&mut self, address: String, factory: Arc<dyn Fn() -> Box<dyn Actor<T>> + Send + Sync>, strategy: RestartStrategy) {
+    self.strategies.insert(address.clone(), strategy);
+    let (high_water, low_water) = (self.high_water, self.low_water);
+    let account = self.accounts.entry(address.clone())
+      .or_insert_with(|| Account::new(high_water, low_water))
+      .clone();
+    let (sender, call_sender) = spawn_actor(address.clone(), factory, account, self.mailbox.clone());
+    self.live += 1;
+    self.senders.entry(address.clone()).or_insert_with(Vec::new).push(sender);
+    self.call_senders.entry(address).or_insert_with(Vec::new).push(call_sender);
+  }
+
+  /// tears down every recipient mounted at `address`. Dropping
+  /// their SyncSenders closes each instance's input channel,
+  /// which ends its `receiver.iter()` loop and lets it
+  /// shut down normally (Stopped is emitted from its own thread).
+  /// The address's Account is kept, so events it already sent
+  /// are still decremented when System::run drains them.
+  fn unmount(&mut self, address: &str) {
+    self.senders.remove(address);
+    self.call_senders.remove(address);
+    self.strategies.remove(address);
+  }
+
+  /// delivers a timer-fired message the same way a Send
+  /// event would be routed. Takes the senders map directly
+  /// (rather than &self) so it can still be called after
+  /// self has been partially borrowed elsewhere in run().
+  fn deliver(senders: &HashMap<String, Vec<SyncSender<T>>>, round_robin: &mut RoundRobin, to: String, value: T, f: &impl Fn(SystemEvent) -> ()) {
+    f(SystemEvent::Forward(format!("timer"), to.clone(), 0));
+    match senders.get(&to) {
+      None => f(SystemEvent::Error(to, format!("does not exist"))),
+      Some(ref vec) => {
+        let sender = &vec[round_robin.next(to.clone())];
+        match sender.send(value) {
+          Err(_) => f(SystemEvent::Error(to, format!("send error"))),
+          Ok(_) => {},
+        }
+      }
     }
   }
 
@@ -228,43 +697,234 @@ impl<T> System<T> where T: Send + Clone + 'static {
   /// will block until all actors have run
   /// to completion. messages are emitted
   /// to the given function F.
-  pub fn run<F>(self, f: F) where F: Fn(SystemEvent) -> () {
+  pub fn run<F>(mut self, f: F) where F: Fn(SystemEvent) -> () {
+    // nothing was ever mounted, so no ActorEvent::Stopped will
+    // ever arrive to trip the live == 0 break below; return
+    // immediately rather than blocking on self.inbox.recv() forever.
+    if self.live == 0 {
+      return
+    }
     let mut round_robin = RoundRobin::new(&self.senders);
-    for event in select(self.receivers) {
+    // min-heap of pending timer deliveries, keyed by fire time.
+    let mut timers: BinaryHeap<Reverse<TimerEntry<T>>> = BinaryHeap::new();
+    // live recurring intervals, keyed by id. removing an entry
+    // here is what cancel_interval() ultimately causes: the
+    // next firing simply will not be re-armed.
+    let mut intervals: HashMap<u64, Duration> = HashMap::new();
+    let mut seq: u64 = 0;
+
+    loop {
+      // wait for either the next actor event or the next
+      // scheduled deadline, whichever comes first, instead of
+      // blocking indefinitely on recv().
+      let next_deadline = timers.peek().map(|Reverse(entry)| entry.fire_at);
+      let event = match next_deadline {
+        Some(fire_at) => {
+          let wait = fire_at.saturating_duration_since(Instant::now());
+          match self.inbox.recv_timeout(wait) {
+            Ok(event) => Some(event),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => break
+          }
+        },
+        None => match self.inbox.recv() {
+          Ok(event) => Some(event),
+          Err(_) => break
+        }
+      };
+
       match event {
-        // general actor events.
-        ActorEvent::Started(address) => f(SystemEvent::Started(address)),
-        ActorEvent::Stopped(address) => f(SystemEvent::Stopped(address)),
-        // sends to one actor.
-        ActorEvent::Send(from, to, value) => {
-          f(SystemEvent::Forward(from.clone(), to.clone(), 0));
-          match self.senders.get(&to) {
-            None => f(SystemEvent::Error(to, format!("does not exist"))),
-            Some(ref mut vec) => {
-              let sender = &vec[round_robin.next(to.clone())];
-              match sender.send(value) {
-                Err(_) => f(SystemEvent::Error(to, format!("send error"))),
-                Ok(_) => {},
+        None => {
+          // no actor event arrived before the deadline: flush
+          // every timer whose fire time has passed.
+          let now = Instant::now();
+          while let Some(Reverse(entry)) = timers.peek() {
+            if entry.fire_at > now {
+              break
+            }
+            let Reverse(entry) = timers.pop().unwrap();
+            let fire_at = entry.fire_at;
+            let ScheduledMessage { to, value, interval } = entry.message;
+            if let Some((id, every)) = interval {
+              // only re-arm if the interval has not been cancelled.
+              if intervals.contains_key(&id) {
+                seq += 1;
+                timers.push(Reverse(TimerEntry {
+                  // re-arm from the deadline that just fired, not
+                  // from `now`, so a late flush does not push the
+                  // next firing back and compound into permanent
+                  // drift.
+                  fire_at: fire_at + every,
+                  seq,
+                  message: ScheduledMessage { to: to.clone(), value: value.clone(), interval: Some((id, every)) }
+                }));
               }
             }
+            Self::deliver(&self.senders, &mut round_robin, to, value, &f);
           }
         },
-        // sends to many actors
-        ActorEvent::Publish(from, to, value) => {
-          f(SystemEvent::Forward(from.clone(), to.clone(), 0));
-          match self.senders.get(&to) {
-            None => f(SystemEvent::Error(to, format!("does not exist"))),
-            Some(ref mut vec) => {
-              for sender in vec.iter() {
-                match sender.send(value.clone()) {
-                  Err(_) => f(SystemEvent::Error(to.clone(), format!("send error"))),
+        Some(event) => match event {
+          // general actor events.
+          ActorEvent::Started(address) => f(SystemEvent::Started(address)),
+          ActorEvent::Stopped(address) => {
+            f(SystemEvent::Stopped(address));
+            self.live -= 1;
+            if self.live == 0 {
+              break
+            }
+          },
+          // sends to one actor.
+          ActorEvent::Send(from, to, value) => {
+            f(SystemEvent::Forward(from.clone(), to.clone(), 0));
+            match self.senders.get(&to) {
+              None => f(SystemEvent::Error(to, format!("does not exist"))),
+              Some(ref vec) => {
+                let sender = &vec[round_robin.next(to.clone())];
+                match sender.send(value) {
+                  Err(_) => f(SystemEvent::Error(to, format!("send error"))),
                   Ok(_) => {},
                 }
               }
             }
+            // the message has now been handed off (or failed to
+            // be), so the sender's debt is settled either way.
+            if let Some(account) = self.accounts.get(&from) {
+              account.decrement();
+              f(SystemEvent::Pressure(from, account.debt()));
+            }
+          },
+          // sends to many actors
+          ActorEvent::Publish(from, to, value) => {
+            f(SystemEvent::Forward(from.clone(), to.clone(), 0));
+            match self.senders.get(&to) {
+              None => f(SystemEvent::Error(to, format!("does not exist"))),
+              Some(ref vec) => {
+                for sender in vec.iter() {
+                  match sender.send(value.clone()) {
+                    Err(_) => f(SystemEvent::Error(to.clone(), format!("send error"))),
+                    Ok(_) => {},
+                  }
+                }
+              }
+            }
+            if let Some(account) = self.accounts.get(&from) {
+              account.decrement();
+              f(SystemEvent::Pressure(from, account.debt()));
+            }
+          },
+          // request/response to one actor, reply is forwarded
+          // to the caller's reply channel by the recipient.
+          ActorEvent::Call(from, to, value, reply) => {
+            f(SystemEvent::Forward(from.clone(), to.clone(), 0));
+            match self.call_senders.get(&to) {
+              // dropping `reply` here closes the caller's receiver.
+              None => f(SystemEvent::Error(to, format!("call target gone"))),
+              Some(ref vec) => {
+                let sender = &vec[round_robin.next(to.clone())];
+                let call = Call { value, reply };
+                if let Err(_) = sender.send(call) {
+                  f(SystemEvent::Error(to, format!("call target gone")))
+                }
+              }
+            }
+          },
+          // arms a one-shot timer.
+          ActorEvent::Schedule(_from, to, value, delay) => {
+            seq += 1;
+            timers.push(Reverse(TimerEntry {
+              fire_at: Instant::now() + delay,
+              seq,
+              message: ScheduledMessage { to, value, interval: None }
+            }));
+          },
+          // arms a recurring timer.
+          ActorEvent::Interval(_from, to, value, every, id) => {
+            intervals.insert(id, every);
+            seq += 1;
+            timers.push(Reverse(TimerEntry {
+              fire_at: Instant::now() + every,
+              seq,
+              message: ScheduledMessage { to, value, interval: Some((id, every)) }
+            }));
+          },
+          // stops a recurring timer from re-arming.
+          ActorEvent::CancelInterval(id) => {
+            intervals.remove(&id);
+          },
+          // consults the address's RestartStrategy and tells
+          // the actor's thread whether it may restart.
+          ActorEvent::Panicked(address, reason, attempt, decision) => {
+            let allow = match self.strategies.get(&address) {
+              None | Some(RestartStrategy::Never) => false,
+              Some(RestartStrategy::Always) => true,
+              Some(RestartStrategy::UpTo(n)) => attempt <= *n
+            };
+            if allow {
+              f(SystemEvent::Restarted(address, attempt));
+            } else {
+              f(SystemEvent::Error(address, reason));
+            }
+            let _ = decision.send(allow);
+          },
+          // answers whether any actor is mounted at address.
+          ActorEvent::Discover(address, reply) => {
+            let exists = self.senders.get(&address).map(|vec| !vec.is_empty()).unwrap_or(false);
+            let _ = reply.send(exists);
+          },
+          // registers a new actor at runtime and brings its
+          // address's round-robin counter up to date.
+          ActorEvent::Spawn(address, factory, strategy) => {
+            self.spawn_and_register(address.clone(), factory, strategy);
+            let total = self.senders.get(&address).map(|vec| vec.len()).unwrap_or(0);
+            round_robin.rebuild(&address, total);
+            f(SystemEvent::Registered(address));
+          },
+          // deregisters every actor mounted at address.
+          ActorEvent::Unmount(address) => {
+            self.unmount(&address);
+            round_robin.remove(&address);
+            f(SystemEvent::Deregistered(address));
+          },
+          // forwards the lifecycle transition on, so callers can
+          // observe Blocked vs Running without polling.
+          ActorEvent::State(address, state) => {
+            f(SystemEvent::StateChanged(address, state));
           }
         }
       }
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// drives an Account's debt up to `high_water` from one thread
+  /// while another is parked in throttle(), then drains it back
+  /// down to `low_water` and checks the parked thread wakes.
+  #[test]
+  fn throttle_parks_at_high_water_and_wakes_at_low_water() {
+    let account = Account::new(4, 1);
+    for _ in 0..4 {
+      account.increment();
+    }
+    let parked = Arc::new(AtomicU8::new(0));
+    let woke = parked.clone();
+    let throttling = account.clone();
+    let handle = thread::spawn(move || {
+      throttling.throttle();
+      woke.store(1, Ordering::SeqCst);
+    });
+
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(parked.load(Ordering::SeqCst), 0, "debt is at high_water, throttle() should still be parked");
+
+    for _ in 0..3 {
+      account.decrement();
+    }
+    handle.join().unwrap();
+    assert_eq!(parked.load(Ordering::SeqCst), 1, "debt drained to low_water, throttle() should have returned");
+    assert_eq!(account.debt(), 1);
+  }
+}
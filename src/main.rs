@@ -28,13 +28,13 @@ THE SOFTWARE.
 
 mod crimson;
 
-use crimson::{Actor, Receiver, Sender, System};
+use crimson::{Actor, Call, Receiver, RestartStrategy, Sender, System, TrackedReceiver};
 
 type Message = &'static str;
 
 struct A;
 impl Actor<Message> for A {
-    fn run(&mut self, sender: Sender<Message>, _: Receiver<Message>) {
+    fn run(&mut self, sender: Sender<Message>, _: &TrackedReceiver<Message>, _: &Receiver<Call<Message>>) {
         sender.send("B", "Hello").unwrap();
         sender.send("B", "World").unwrap();
     }
@@ -42,16 +42,16 @@ impl Actor<Message> for A {
 
 struct B;
 impl Actor<Message> for B {
-    fn run(&mut self, _: Sender<Message>, receiver: Receiver<Message>) {
-        for message in receiver {
+    fn run(&mut self, _: Sender<Message>, receiver: &TrackedReceiver<Message>, _: &Receiver<Call<Message>>) {
+        for message in receiver.iter() {
             println!("B {}", message)
         }
     }
 }
 
 fn main() {
-    let mut system = System::new();
-    system.mount("A", Box::new(A));
-    system.mount("B", Box::new(B));
+    let mut system = System::new(8, 2);
+    system.mount("A", || Box::new(A), RestartStrategy::Never);
+    system.mount("B", || Box::new(B), RestartStrategy::Never);
     system.run(|info| println!("{:?}", info));
 }